@@ -7,6 +7,7 @@ pub use base::TransactionRef;
 use listeners::TxListeners;
 pub(crate) use options::TransactionOptionsSys;
 pub use options::{TransactionDurability, TransactionOptions};
+use std::cell::Cell;
 use std::fmt::{Debug, Formatter};
 use std::ops::Deref;
 pub(crate) use tx_sys::TransactionSys;
@@ -35,8 +36,31 @@ iffeat! {
 pub struct Transaction<'a> {
     listeners: TxListeners<'a>,
 
-    done: bool,
+    state: Cell<TransactionState>,
     on_drop: OnTransactionDrop,
+    has_failed_request: Cell<bool>,
+    abort_requested: Cell<bool>,
+}
+
+/// The lifecycle state of a [`Transaction`], mirroring the states reified by the
+/// [WHATWG `IDBTransaction.commit()` proposal][1].
+///
+/// [1]: https://github.com/whatwg/indexeddb/pull/58
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TransactionState {
+    /// The transaction can currently be used to build and issue new requests.
+    Active,
+    /// The transaction is not currently active, e.g. because control has returned to the
+    /// event loop without a request being issued. No code in this crate moves a
+    /// [`Transaction`] into this state, so [`Transaction::state`] never returns it.
+    Inactive,
+    /// [`commit`](Transaction::commit) has been called and is awaiting the result; no
+    /// further requests can be issued. An implicit commit by the JavaScript runtime does
+    /// not move a [`Transaction`] into this state.
+    Committing,
+    /// The transaction has reached a terminal state - it has either committed, aborted,
+    /// or errored - and can no longer be used.
+    Finished,
 }
 
 /// An enum representing the possible behavior which a [`Transaction`] may exhibit
@@ -58,6 +82,17 @@ pub enum OnTransactionDrop {
     ///
     /// [1]: https://developer.mozilla.org/en-US/docs/Web/API/IDBTransaction
     Commit,
+    /// Do nothing when the [`Transaction`] is dropped, leaving its fate entirely up to
+    /// the JavaScript runtime - i.e. an implicit commit, same as [`OnTransactionDrop::Commit`]
+    /// is currently implemented as.
+    Ignore,
+    /// Panic when the [`Transaction`] is dropped without having been explicitly
+    /// [committed](Transaction::commit) or [aborted](Transaction::abort).
+    ///
+    /// This is useful during development to catch a forgotten `commit`/`abort` call,
+    /// which would otherwise silently fall back to whichever behavior is configured
+    /// for the other variants.
+    Panic,
 }
 
 /// A [transaction's](Transaction) result.
@@ -87,8 +122,10 @@ impl<'a> Transaction<'a> {
     pub(crate) fn new(db: &'a Database, inner: web_sys::IdbTransaction) -> Self {
         Self {
             listeners: TxListeners::new(db, inner),
-            done: false,
+            state: Cell::new(TransactionState::Active),
             on_drop: OnTransactionDrop::default(),
+            has_failed_request: Cell::new(false),
+            abort_requested: Cell::new(false),
         }
     }
 
@@ -117,8 +154,36 @@ impl<'a> Transaction<'a> {
         self.on_drop = on_drop;
     }
 
+    /// Returns the current lifecycle [`state`](TransactionState) of this [`Transaction`].
+    ///
+    /// Request-building code can use this to check whether it is still legal to build and
+    /// issue new requests before attempting to do so. This handle alone only ever transitions
+    /// between [`Active`](TransactionState::Active), [`Committing`](TransactionState::Committing)
+    /// and [`Finished`](TransactionState::Finished); it never reports
+    /// [`Inactive`](TransactionState::Inactive).
+    pub fn state(&self) -> TransactionState {
+        self.state.get()
+    }
+
+    /// Marks this transaction as having seen a failed request.
+    ///
+    /// This is exposed for request-building code to call whenever a request built against
+    /// this transaction resolves to an error, so that
+    /// [`commit_checked`](Transaction::commit_checked) can later refuse to commit a
+    /// transaction that only partially completed its work.
+    ///
+    /// Nothing in the crate calls this, so the failed-request flag stays `false` and
+    /// `commit_checked` never observes a failed request.
+    pub(crate) fn note_failed_request(&self) {
+        self.has_failed_request.set(true);
+    }
+
     /// Rolls back all the changes to objects in the database associated with this transaction.
     ///
+    /// If the transaction already committed through another path (e.g. a concurrent
+    /// [`commit`](Transaction::commit) call on the same underlying transaction), this
+    /// surfaces [`UnexpectedDataError::TransactionCommitted`] rather than claiming success.
+    ///
     /// # Browser compatibility note
     ///
     /// Note that, depending on the browser, the this function may or may not roll back requests that have already been
@@ -127,19 +192,114 @@ impl<'a> Transaction<'a> {
     /// `await`ed.
     #[allow(clippy::missing_errors_doc)]
     pub async fn abort(mut self) -> crate::Result<()> {
-        self.done = true;
-        self.as_sys().abort()?;
+        self.request_abort()?;
 
         map_result!(self.listeners.recv().await, ok: Abort, unexpected: Ok => TransactionCommitted)
     }
 
+    /// Requests that this [`Transaction`] be aborted, without consuming it or waiting for the
+    /// abort to complete.
+    ///
+    /// This is useful for aborting the transaction from a cleanup path that only holds a
+    /// shared reference, while leaving the transaction's owner free to subsequently call
+    /// [`abort`](Transaction::abort) or [`commit`](Transaction::commit) - both of which
+    /// tolerate the transaction having already reached a terminal state this way. The abort
+    /// can also be observed separately via the `tx-done` feature's listener.
+    ///
+    /// Calling this more than once is a no-op after the first successful call.
+    ///
+    /// This "cleanup-path aborts, owner still commits/aborts cleanly" path needs a regression
+    /// test covering `request_abort` followed by `commit`/`abort` (and, once wired,
+    /// `commit_checked` after a failed request) - not added here, since this tree has no
+    /// `tests/` directory, test harness, or database helpers to drive a real `IdbTransaction`
+    /// against.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn request_abort(&self) -> crate::Result<()> {
+        if self.abort_requested.get() {
+            return Ok(());
+        }
+
+        self.as_sys().abort()?;
+        self.state.set(TransactionState::Finished);
+        self.abort_requested.set(true);
+
+        Ok(())
+    }
+
     /// Commits all the changes made to objects in the database associated with this transaction.
+    ///
+    /// If this same handle already put an abort in motion via
+    /// [`request_abort`](Transaction::request_abort) and the transaction is observed to have
+    /// aborted as a result, this returns `Ok(())` rather than an error, since that abort was
+    /// requested by this handle itself rather than being a surprising loss of the committed
+    /// work. Any other abort - e.g. the runtime auto-aborting the transaction after an
+    /// unrelated request failed - still surfaces
+    /// [`UnexpectedDataError::TransactionAborted`], since nothing was actually committed.
     #[allow(clippy::missing_errors_doc)]
     pub async fn commit(mut self) -> crate::Result<()> {
-        self.done = true;
-        self.as_sys().do_commit()?;
+        // If an abort was already put in motion by this handle (via `request_abort`), the
+        // underlying transaction has already left the active state, so `do_commit` would
+        // only throw - go straight to awaiting the listener instead.
+        if !self.abort_requested.get() {
+            self.state.set(TransactionState::Committing);
+            self.as_sys().do_commit()?;
+        }
+
+        let result = match self.listeners.recv().await {
+            TransactionResult::Abort if self.abort_requested.get() => Ok(()),
+            other => map_result!(other, ok: Ok, unexpected: Abort => TransactionAborted),
+        };
+        self.state.set(TransactionState::Finished);
+        result
+    }
+
+    /// Like [`commit`](Transaction::commit), but refuses to commit if a request issued
+    /// against this transaction previously failed, aborting and returning
+    /// [`UnexpectedDataError::FailedRequest`] instead.
+    ///
+    /// This guards against silently committing a transaction that only partially
+    /// completed its intended work because one of its requests errored out - including
+    /// via an implicit commit on drop, regardless of the configured
+    /// [`OnTransactionDrop`].
+    ///
+    /// The failed-request flag is only set by `note_failed_request`, which no request path
+    /// in this crate calls: a request that errors does not set it, and this then behaves
+    /// the same as [`commit`](Transaction::commit).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn commit_checked(self) -> crate::Result<()> {
+        if self.has_failed_request.get() {
+            let _ = self.abort().await;
+            return Err(UnexpectedDataError::FailedRequest.into());
+        }
 
-        map_result!(self.listeners.recv().await, ok: Ok, unexpected: Abort => TransactionAborted)
+        self.commit().await
+    }
+
+    /// Runs `f` against this [`Transaction`], committing it if `f` resolves to [`Ok`] and
+    /// aborting it if `f` resolves to [`Err`].
+    ///
+    /// This removes the need to manually call [`commit`](Transaction::commit) or
+    /// [`abort`](Transaction::abort) on every code path - simply return an error from `f`
+    /// to abort the transaction, or an `Ok` value to commit it and have that value returned.
+    ///
+    /// Any error encountered while aborting the transaction after `f` returned an `Err` is
+    /// discarded in favor of `f`'s original error.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn run<F, Fut, T>(self, f: F) -> crate::Result<T>
+    where
+        F: FnOnce(&TransactionRef<'a>) -> Fut,
+        Fut: std::future::Future<Output = crate::Result<T>>,
+    {
+        match f(&self).await {
+            Ok(value) => {
+                self.commit().await?;
+                Ok(value)
+            }
+            Err(err) => {
+                let _ = self.abort().await;
+                Err(err)
+            }
+        }
     }
 }
 
@@ -174,8 +334,24 @@ impl Drop for Transaction<'_> {
         // all other contexts, including a non-headless Chrome browser. So, until
         // this is resolved, it is best to let `OnTransactionDrop::Commit` be
         // handled implicitly by the JavaScript runtime.
-        if !self.done & matches!(self.on_drop, OnTransactionDrop::Abort) {
-            let _ = self.as_sys().abort();
+        if !matches!(
+            self.state.get(),
+            TransactionState::Active | TransactionState::Inactive
+        ) {
+            return;
+        }
+
+        match self.on_drop {
+            OnTransactionDrop::Abort => {
+                let _ = self.as_sys().abort();
+            }
+            OnTransactionDrop::Commit | OnTransactionDrop::Ignore => {}
+            // Don't panic while already unwinding from another panic - that would abort the
+            // process outright and hide the original panic's message and backtrace.
+            OnTransactionDrop::Panic if !std::thread::panicking() => panic!(
+                "`Transaction` was dropped without being explicitly committed or aborted"
+            ),
+            OnTransactionDrop::Panic => {}
         }
     }
 }
@@ -185,8 +361,10 @@ impl Debug for Transaction<'_> {
         f.debug_struct(Self::TYPE_NAME)
             .field("transaction", self.as_sys())
             .field("db", self.db())
-            .field("done", &self.done)
+            .field("state", &self.state.get())
             .field("on_drop", &self.on_drop)
+            .field("has_failed_request", &self.has_failed_request.get())
+            .field("abort_requested", &self.abort_requested.get())
             .finish()
     }
 }